@@ -1,5 +1,5 @@
 use anyhow::{Context, Result, anyhow, bail};
-use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 use std::env::home_dir;
 
 use std::collections::{HashMap, HashSet};
@@ -8,6 +8,7 @@ use std::fs;
 use std::io::Write;
 use std::io::{stdin, stdout};
 use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 enum Command {
     Load,
@@ -16,27 +17,57 @@ enum Command {
     View,
     Open,
     Edit,
+    Clone,
+    Tags,
     Reset,
     Help,
 }
 
-type Projects = HashMap<String, PathBuf>;
+/// A saved project: its directory plus the access stats frecency ranking is derived from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Project {
+    path: PathBuf,
+    #[serde(default)]
+    count: u64,
+    #[serde(default)]
+    last_access: u64,
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    tags: HashSet<String>,
+}
+
+type Projects = HashMap<String, Project>;
+
+/// On-disk `.fstore` shape; `version` lets future releases detect and migrate older formats
+#[derive(Serialize, Deserialize)]
+struct Store {
+    version: u32,
+    projects: Projects,
+}
 
+const STORE_VERSION: u32 = 2;
 const NO_PROJECTS_ERROR: &str = "No saved projects found";
 
+const HOUR_SECS: u64 = 60 * 60;
+const DAY_SECS: u64 = 24 * HOUR_SECS;
+const WEEK_SECS: u64 = 7 * DAY_SECS;
+const STALE_THRESHOLD_SECS: u64 = 90 * DAY_SECS;
+
 /* Main */
 
 fn main() -> Result<()> {
-    let (command, query) = parse_args()?;
+    let (command, query, tag) = parse_args()?;
     let mut projects = read_projects()?;
+    let tag = tag.as_deref();
 
     match command {
-        Command::Load => load_project(&query, &projects),
+        Command::Load => load_project(&query, &mut projects, tag),
         Command::Save => save_project(&query, &mut projects),
-        Command::Delete => delete_project(&query, &mut projects),
-        Command::View => view_project(&query, &projects),
-        Command::Open => open_project(&query, &projects),
-        Command::Edit => edit_project(&query, &projects),
+        Command::Delete => delete_project(&query, &mut projects, tag),
+        Command::View => view_project(&query, &projects, tag),
+        Command::Open => open_project(&query, &projects, tag),
+        Command::Edit => edit_project(&query, &projects, tag),
+        Command::Clone => clone_project(&query, &mut projects),
+        Command::Tags => print_tags(&projects),
         Command::Reset => reset_projects(&projects),
         Command::Help => {
             print_help();
@@ -45,40 +76,39 @@ fn main() -> Result<()> {
     }
 }
 
-fn parse_args() -> Result<(Command, String)> {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() > 3 {
-        bail!("Too many arguments provided");
-    }
-
-    let has_flag = args.len() > 1 && args[1].starts_with('-');
-    let command = if has_flag {
-        // Parse first argument as a flag, second argument as query
-        match args[1].as_ref() {
-            "-h" | "--help" => Command::Help,
-            "-s" | "--save" => Command::Save,
-            "-d" | "--delete" => Command::Delete,
-            "-v" | "--view" => Command::View,
-            "-o" | "--open" => Command::Open,
-            "-e" | "--edit" => Command::Edit,
-            "--reset" => Command::Reset,
-            _ => {
-                bail!("Unrecognized argument provided: {}", args[1]);
+fn parse_args() -> Result<(Command, String, Option<String>)> {
+    let mut command = None;
+    let mut query = None;
+    let mut tag = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => command = Some(Command::Help),
+            "-s" | "--save" => command = Some(Command::Save),
+            "-d" | "--delete" => command = Some(Command::Delete),
+            "-v" | "--view" => command = Some(Command::View),
+            "-o" | "--open" => command = Some(Command::Open),
+            "-e" | "--edit" => command = Some(Command::Edit),
+            "-c" | "--clone" => command = Some(Command::Clone),
+            "--tags" => command = Some(Command::Tags),
+            "--reset" => command = Some(Command::Reset),
+            "--tag" => {
+                tag = Some(args.next().context("Expected a tag name after --tag")?);
+            }
+            _ if arg.starts_with('-') => {
+                bail!("Unrecognized argument provided: {}", arg);
             }
+            _ if query.is_none() => query = Some(arg),
+            _ => bail!("Too many arguments provided"),
         }
-    } else {
-        // Parse first argument as query
-        Command::Load
-    };
-
-    // Query may or may not be provided
-    let query_index = if has_flag { 2 } else { 1 };
-    let query = args
-        .get(query_index)
-        .map_or_else(String::new, String::to_owned);
+    }
 
-    Ok((command, query))
+    Ok((
+        command.unwrap_or(Command::Load),
+        query.unwrap_or_default(),
+        tag,
+    ))
 }
 
 /* Store */
@@ -87,53 +117,228 @@ fn read_projects() -> Result<Projects> {
     let store = get_store_path()?;
     if !store.exists() {
         // Return empty map if file does not exist
-        Ok(Projects::new())
-    } else {
-        let serialized = fs::read_to_string(store)?;
-        serde_json::from_str(&serialized).context("Failed to read projects from disk")
+        return Ok(Projects::new());
+    }
+
+    let serialized = fs::read_to_string(&store)?;
+    let mut projects = match serde_json::from_str::<Store>(&serialized) {
+        Ok(data) => data.projects,
+        // Pre-v2 stores were a flat `{name: path}` map with no version field
+        Err(_) => migrate_legacy_store(&serialized)?,
+    };
+
+    if prune_stale_projects(&mut projects) {
+        write_projects(&projects)?;
     }
+
+    Ok(projects)
 }
 
 fn write_projects(projects: &Projects) -> Result<()> {
     let store = get_store_path()?;
-    let serialized = serde_json::to_string(projects)?;
+    let data = Store {
+        version: STORE_VERSION,
+        projects: projects.clone(),
+    };
+    let serialized = serde_json::to_string(&data)?;
     fs::write(store, serialized).context("Failed to write projects to disk")
 }
 
+/// Reads the pre-v2 `.fstore` format (a flat `{name: path}` map) and migrates it to versioned records
+fn migrate_legacy_store(serialized: &str) -> Result<Projects> {
+    let legacy: HashMap<String, PathBuf> =
+        serde_json::from_str(serialized).context("Failed to read projects from disk")?;
+
+    Ok(legacy
+        .into_iter()
+        .map(|(name, path)| {
+            (
+                name,
+                Project {
+                    path,
+                    count: 0,
+                    last_access: 0,
+                    tags: HashSet::new(),
+                },
+            )
+        })
+        .collect())
+}
+
+/// Drops projects whose directory no longer exists and have gone unaccessed for 90+ days
+/// Returns whether anything was pruned, so the caller knows to persist the updated map
+fn prune_stale_projects(projects: &mut Projects) -> bool {
+    let now = now_unix();
+    let before = projects.len();
+
+    projects.retain(|_, project| {
+        project.path.exists() || now.saturating_sub(project.last_access) < STALE_THRESHOLD_SECS
+    });
+
+    projects.len() != before
+}
+
+/// Records an access to `name`, bumping its frecency count and timestamp, then persists the change
+fn touch_project(projects: &mut Projects, name: &str) -> Result<()> {
+    if let Some(project) = projects.get_mut(name) {
+        project.count += 1;
+        project.last_access = now_unix();
+    }
+    write_projects(projects)
+}
+
+fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// Frecency decay factor: recently accessed projects are weighted far higher than stale ones
+fn decay(elapsed_secs: u64) -> f64 {
+    if elapsed_secs < HOUR_SECS {
+        4.0
+    } else if elapsed_secs < DAY_SECS {
+        2.0
+    } else if elapsed_secs < WEEK_SECS {
+        0.5
+    } else {
+        0.25
+    }
+}
+
+/// Combines access frequency and recency into a single ranking score
+fn frecency(project: &Project, now: u64) -> f64 {
+    project.count as f64 * decay(now.saturating_sub(project.last_access))
+}
+
+/// Restricts `projects` to entries carrying `tag`, or returns an unfiltered clone if `tag` is `None`
+fn filter_by_tag(projects: &Projects, tag: Option<&str>) -> Projects {
+    match tag {
+        Some(tag) => projects
+            .iter()
+            .filter(|(_, project)| project.tags.contains(tag))
+            .map(|(name, project)| (name.clone(), project.clone()))
+            .collect(),
+        None => projects.clone(),
+    }
+}
+
 /* Commands */
 
-fn load_project(query: &str, projects: &Projects) -> Result<()> {
-    let (project, path) = select_project(query, projects, "Which project should be loaded?")?;
+fn load_project(query: &str, projects: &mut Projects, tag: Option<&str>) -> Result<()> {
+    let (project, path) = {
+        let filtered = filter_by_tag(projects, tag);
+        let (project, record) = select_project(query, &filtered, "Which project should be loaded?")?;
+        (project.clone(), record.path.clone())
+    };
 
-    if *path == current_dir()? {
+    if path == current_dir()? {
         bail!("Already in project directory");
     }
 
     println!("Switching to \"{project}\"");
-    send_to_shell("cd", path)?;
+    touch_project(projects, &project)?;
+    send_to_shell("cd", &path)?;
     Ok(())
 }
 
 fn save_project(query: &str, projects: &mut Projects) -> Result<()> {
-    let project = if query.is_empty() {
-        user_input("Enter new project name: ")?
+    let (input, path) = if query.is_empty() {
+        detect_project_root(&current_dir()?)?
     } else {
-        query.to_string()
+        (query.to_string(), current_dir()?)
     };
+    let (project, tags) = parse_name_and_tags(&input);
 
     let message = format!("Project named \"{}\" already exists. Overwrite", project);
     if !projects.contains_key(&project) || user_confirms(message)? {
         println!("Saved project \"{project}\"");
 
-        projects.insert(project, current_dir()?);
+        // Keep existing tags unless new ones were explicitly provided via `name:tag1,tag2`
+        let tags = tags.unwrap_or_else(|| {
+            projects
+                .get(&project)
+                .map(|record| record.tags.clone())
+                .unwrap_or_default()
+        });
+
+        let record = Project {
+            path,
+            count: 0,
+            last_access: now_unix(),
+            tags,
+        };
+        projects.insert(project, record);
         write_projects(projects)
     } else {
         Ok(())
     }
 }
 
-fn delete_project(query: &str, projects: &mut Projects) -> Result<()> {
-    let (project, _) = select_project(query, projects, "Which project should be deleted?")?;
+/// Markers that identify a directory as a repository or project root
+const PROJECT_MARKERS: [&str; 6] = [
+    ".git",
+    ".hg",
+    ".svn",
+    "Cargo.toml",
+    "package.json",
+    ".projectile",
+];
+
+/// Walks upward from `dir` for a project root marker and, if found, asks the user to confirm
+/// saving that ancestor (using its basename as the default name) instead of the bare cwd
+/// Falls back to prompting for a name against `dir` if no marker is found or the user declines
+fn detect_project_root(dir: &Path) -> Result<(String, PathBuf)> {
+    if let Some(root) = find_project_root(dir) {
+        let name = root
+            .file_name()
+            .context("Could not determine project name from path")?
+            .to_string_lossy()
+            .to_string();
+
+        let message = format!("Save \"{name}\" at {}", tilde_path(&root)?);
+        if user_confirms(message)? {
+            return Ok((name, root));
+        }
+    }
+
+    Ok((user_input("Enter new project name: ")?, dir.to_path_buf()))
+}
+
+/// Searches `dir` and its ancestors for the closest one containing a project marker
+fn find_project_root(dir: &Path) -> Option<PathBuf> {
+    let mut current = Some(dir);
+    while let Some(dir) = current {
+        if PROJECT_MARKERS.iter().any(|marker| dir.join(marker).exists()) {
+            return Some(dir.to_path_buf());
+        }
+        current = dir.parent();
+    }
+    None
+}
+
+/// Splits a `save` input of the form `name:tag1,tag2` into the project name and its tags
+/// Returns `None` for the tags half if no `:` was present, so callers can distinguish "no tags
+/// given" from "explicitly cleared tags"
+fn parse_name_and_tags(input: &str) -> (String, Option<HashSet<String>>) {
+    match input.split_once(':') {
+        Some((name, tags)) => {
+            let tags = tags
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(String::from)
+                .collect();
+            (name.to_string(), Some(tags))
+        }
+        None => (input.to_string(), None),
+    }
+}
+
+fn delete_project(query: &str, projects: &mut Projects, tag: Option<&str>) -> Result<()> {
+    let filtered = filter_by_tag(projects, tag);
+    let (project, _) = select_project(query, &filtered, "Which project should be deleted?")?;
 
     let message = format!("Delete \"{project}\"");
     if user_confirms(message)? {
@@ -146,28 +351,35 @@ fn delete_project(query: &str, projects: &mut Projects) -> Result<()> {
     Ok(())
 }
 
-fn view_project(query: &str, projects: &Projects) -> Result<()> {
-    let (project, path) = select_project(
+fn view_project(query: &str, projects: &Projects, tag: Option<&str>) -> Result<()> {
+    let filtered = filter_by_tag(projects, tag);
+    let (project, record) = select_project(
         query,
-        projects,
+        &filtered,
         "Which project should open in the file explorer?",
     )?;
 
     println!("Opening \"{project}\" in file explorer...");
-    open_native(path)
+    open_native(&record.path)
 }
 
-fn open_project(query: &str, projects: &Projects) -> Result<()> {
-    let (project, path) = select_project(query, projects, "Which project would you like to open?")?;
-    let path = PathBuf::from(path);
+fn open_project(query: &str, projects: &Projects, tag: Option<&str>) -> Result<()> {
+    let filtered = filter_by_tag(projects, tag);
+    let (project, record) = select_project(query, &filtered, "Which project would you like to open?")?;
+    let path = record.path.clone();
+
+    if let Some(command) = resolve_handler(&path)? {
+        println!("Opening \"{project}\" with \"{command}\"...");
+        return launch_command(&command, &path);
+    }
 
     if path.join("start").is_file() {
         // Start script
         println!("Starting \"{project}\"...");
         set_current_dir(&path)?;
 
-        std::process::Command::new("./start")
-            .spawn()?
+        let cmd = std::process::Command::new("./start");
+        spawn_app(cmd)?
             .wait()
             .map(|_| ())
             .map_err(|e| anyhow!("Failed to execute start script: {}", e))
@@ -187,14 +399,164 @@ fn open_project(query: &str, projects: &Projects) -> Result<()> {
     }
 }
 
-fn edit_project(query: &str, projects: &Projects) -> Result<()> {
+/// Picks the launch command for `path` from `~/.fastrc`'s marker table, prompting to disambiguate
+/// when several markers match; returns `None` when no handler is configured or none match
+fn resolve_handler(path: &Path) -> Result<Option<String>> {
+    let handlers = read_handler_config()?;
+
+    let mut matches: Vec<(&String, &String)> = handlers
+        .iter()
+        .filter(|(marker, _)| handler_marker_matches(marker, path))
+        .collect();
+    matches.sort_by_key(|(marker, _)| marker.as_str());
+
+    match matches.len() {
+        0 => Ok(None),
+        1 => Ok(Some(matches[0].1.clone())),
+        _ => {
+            // Disambiguate through the same prompt/finder flow `select_project` uses, presenting
+            // each candidate as a "project" named after its marker, pointing at its launch command
+            let subset: Projects = matches
+                .into_iter()
+                .map(|(marker, command)| {
+                    (
+                        marker.clone(),
+                        Project {
+                            path: PathBuf::from(command),
+                            count: 0,
+                            last_access: 0,
+                            tags: HashSet::new(),
+                        },
+                    )
+                })
+                .collect();
+
+            let (_, record) = select_project("", &subset, "Multiple handlers match. Open with?")?;
+            Ok(Some(record.path.to_string_lossy().into_owned()))
+        }
+    }
+}
+
+/// Checks whether a handler marker (a bare filename like `Cargo.toml`, or a `*.ext` glob) matches `path`
+fn handler_marker_matches(marker: &str, path: &Path) -> bool {
+    match marker.strip_prefix("*.") {
+        Some(ext) => get_file_with_extension(ext, path).is_some(),
+        None => path.join(marker).is_file() || path.join(marker).is_dir(),
+    }
+}
+
+/// Reads the marker-to-command table from `~/.fastrc`, e.g. `"package.json" = "code ."`
+fn read_handler_config() -> Result<HashMap<String, String>> {
+    let path = get_fastrc_path()?;
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let contents = fs::read_to_string(&path).context("Failed to read ~/.fastrc")?;
+    toml::from_str(&contents).context("Failed to parse ~/.fastrc")
+}
+
+/// Expands `$VAR` tokens in `command`, splits it into a program and arguments, and spawns it in `cwd`
+fn launch_command(command: &str, cwd: &Path) -> Result<()> {
+    let expanded = expand_env_vars(command);
+    let mut parts = expanded.split_whitespace();
+    let program = parts.next().context("Empty handler command")?;
+
+    let mut cmd = std::process::Command::new(program);
+    cmd.args(parts).current_dir(cwd);
+    spawn_app(cmd)?;
+    Ok(())
+}
+
+/// Replaces `$VAR`-style tokens with their environment value, leaving unset ones untouched
+fn expand_env_vars(command: &str) -> String {
+    command
+        .split_whitespace()
+        .map(|token| match token.strip_prefix('$') {
+            Some(name) => env::var(name).unwrap_or_else(|_| token.to_string()),
+            None => token.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn edit_project(query: &str, projects: &Projects, tag: Option<&str>) -> Result<()> {
     let editor = env::var("EDITOR")
         .context("No editor configured. Please set the $EDITOR environment variable")?;
 
+    let filtered = filter_by_tag(projects, tag);
     let message = format!("Which project should be opened with {}?", editor);
-    let (_, path) = select_project(query, projects, &message)?;
+    let (_, record) = select_project(query, &filtered, &message)?;
+
+    send_to_shell(&editor, &record.path)
+}
+
+fn clone_project(url: &str, projects: &mut Projects) -> Result<()> {
+    if url.is_empty() {
+        bail!("Please provide a git URL to clone");
+    }
+
+    let project = derive_clone_name(url)?;
+    let path = get_clone_base_dir()?.join(&project);
 
-    send_to_shell(&editor, path)
+    let message = format!("Project named \"{}\" already exists. Overwrite", project);
+    if projects.contains_key(&project) {
+        if !user_confirms(message)? {
+            return Ok(());
+        }
+        if path.exists() {
+            fs::remove_dir_all(&path)
+                .with_context(|| format!("Failed to remove existing {}", path.display()))?;
+        }
+    }
+
+    println!("Cloning \"{url}\" into {}...", tilde_path(&path)?);
+    let status = std::process::Command::new("git")
+        .args(["clone", url, &path.to_string_lossy()])
+        .status()
+        .context("Failed to run git clone")?;
+
+    if !status.success() {
+        bail!("git clone failed for {url}");
+    }
+
+    println!("Saved project \"{project}\"");
+    projects.insert(
+        project.clone(),
+        Project {
+            path: path.clone(),
+            count: 1,
+            last_access: now_unix(),
+            tags: HashSet::new(),
+        },
+    );
+    write_projects(projects)?;
+
+    println!("Switching to \"{project}\"");
+    send_to_shell("cd", &path)
+}
+
+/// Derives a project name from the trailing slug of a git URL, e.g. `git@host:org/repo.git` -> `repo`
+fn derive_clone_name(url: &str) -> Result<String> {
+    let slug = url
+        .trim_end_matches('/')
+        .trim_end_matches(".git")
+        .rsplit(['/', ':'])
+        .next()
+        .filter(|slug| !slug.is_empty())
+        .context("Could not derive project name from URL")?;
+
+    Ok(slug.to_string())
+}
+
+/// Base directory new clones are checked out into, overridable via `$FAST_CLONE_DIR`
+fn get_clone_base_dir() -> Result<PathBuf> {
+    if let Some(dir) = env::var("FAST_CLONE_DIR").ok().filter(|dir| !dir.is_empty()) {
+        return Ok(PathBuf::from(dir));
+    }
+
+    let home = home_dir().context("Failed to resolve default clone directory")?;
+    Ok(home.join("src"))
 }
 
 fn reset_projects(projects: &Projects) -> Result<()> {
@@ -214,16 +576,15 @@ fn reset_projects(projects: &Projects) -> Result<()> {
 
 /* Utilities */
 
-type Selection<'a> = Result<(&'a String, &'a PathBuf)>;
+type Selection<'a> = Result<(&'a String, &'a Project)>;
 
 /// Selects a project from projects based on query, requesting user for additional input if ambiguous
-/// The lifetime of the returned (project, path) key-value pair is tied to the `projects` map it is retrieved from
+/// The lifetime of the returned (project, record) key-value pair is tied to the `projects` map it is retrieved from
 fn select_project<'a>(query: &str, projects: &'a Projects, prompt: &str) -> Selection<'a> {
     // Helper method to request user for query
     fn query_user<'a>(projects: &'a Projects, prompt: &str) -> Selection<'a> {
-        print_projects(projects, prompt)?;
-        let input = user_input("\nEnter project: ")?;
-        select_project(&input, projects, prompt)
+        let pairs = ranked_pairs(projects);
+        prompt_selection(&pairs, projects, prompt, prompt)
     }
 
     if projects.is_empty() {
@@ -236,32 +597,45 @@ fn select_project<'a>(query: &str, projects: &'a Projects, prompt: &str) -> Sele
     }
 
     // Return exact match if found
-    if let Some((project, path)) = projects.get_key_value(query) {
-        return Ok((project, path));
+    if let Some((project, record)) = projects.get_key_value(query) {
+        return Ok((project, record));
     }
 
-    // Filter project keys containing substring
-    let matches: HashSet<_> = projects
-        .keys()
-        .filter(|project| project.contains(query))
+    // Fuzzy subsequence match against project keys, ranked best-first, frecency breaking ties
+    let now = now_unix();
+    let mut matches: Vec<(&String, &Project, i64)> = projects
+        .iter()
+        .filter_map(|(project, record)| fuzzy_score(query, project).map(|score| (project, record, score)))
         .collect();
+    matches.sort_by(|a, b| {
+        b.2.cmp(&a.2).then_with(|| {
+            frecency(b.1, now)
+                .partial_cmp(&frecency(a.1, now))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    });
 
     match matches.len() {
         0 => {
             bail!("No matching project found");
         }
         1 => {
-            // Retrieve first (and only) project in matches and corresponding path
-            let project = *matches.iter().next().unwrap();
-            let path = projects.get(project).unwrap();
-
-            Ok((project, path))
+            let (project, record, _) = matches[0];
+            Ok((project, record))
+        }
+        _ if matches[0].2 > matches[1].2 => {
+            // Clear winner over the runner-up, auto-select without prompting
+            let (project, record, _) = matches[0];
+            Ok((project, record))
         }
         _ => {
-            // Clone projects and disambiguate from matches
-            let mut subset = projects.clone();
-            subset.retain(|key, _| matches.contains(key));
-            let (key, _) = query_user(&subset, "")?;
+            // Disambiguate from ranked candidates, presented best-first
+            let ranked: Vec<_> = matches.iter().map(|(project, record, _)| (*project, *record)).collect();
+            let mut subset = Projects::new();
+            for (project, record) in &ranked {
+                subset.insert((*project).clone(), (*record).clone());
+            }
+            let (key, _) = prompt_selection(&ranked, &subset, "", prompt)?;
 
             // Return original key-value pair
             Ok(projects.get_key_value(key).unwrap())
@@ -269,6 +643,189 @@ fn select_project<'a>(query: &str, projects: &'a Projects, prompt: &str) -> Sele
     }
 }
 
+/// Returns project/record pairs ordered by frecency score (highest first), name breaking ties
+fn ranked_pairs(projects: &Projects) -> Vec<(&String, &Project)> {
+    let now = now_unix();
+    let mut pairs: Vec<_> = projects.iter().collect();
+    pairs.sort_by(|a, b| {
+        frecency(b.1, now)
+            .partial_cmp(&frecency(a.1, now))
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.cmp(b.0))
+    });
+    pairs
+}
+
+/// Scores how well `query` matches `candidate` as an ordered, case-insensitive subsequence
+/// Returns `None` if `query` is not a subsequence of `candidate`, otherwise a higher-is-better score
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let chars: Vec<char> = candidate.chars().collect();
+    // Lowercase char-by-char (not `str::to_lowercase()`) so `lower` stays aligned with `chars`;
+    // some chars (e.g. 'İ') lowercase to multiple chars when done as a whole string.
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+
+    let mut score: i64 = 0;
+    let mut query_idx = 0;
+    let mut first_match: Option<usize> = None;
+    let mut last_match: Option<usize> = None;
+    let mut consecutive: i64 = 0;
+
+    for (candidate_idx, &c) in lower.iter().enumerate() {
+        if query_idx == query.len() {
+            break;
+        }
+        if c != query[query_idx] {
+            continue;
+        }
+
+        first_match.get_or_insert(candidate_idx);
+
+        // Bonus for matches at word boundaries
+        let is_boundary = candidate_idx == 0
+            || matches!(chars[candidate_idx - 1], '-' | '_' | '/')
+            || (chars[candidate_idx - 1].is_lowercase() && chars[candidate_idx].is_uppercase());
+        if is_boundary {
+            score += 10;
+        }
+
+        // Bonus for consecutive matches, penalty proportional to the gap otherwise
+        match last_match {
+            Some(last) if candidate_idx == last + 1 => {
+                consecutive += 1;
+                score += 5 * consecutive;
+            }
+            Some(last) => {
+                consecutive = 0;
+                score -= (candidate_idx - last - 1) as i64;
+            }
+            None => {}
+        }
+
+        last_match = Some(candidate_idx);
+        query_idx += 1;
+    }
+
+    if query_idx < query.len() {
+        // Not all query characters were matched, in order
+        return None;
+    }
+
+    // Penalty proportional to unmatched characters before the first match
+    score -= first_match.unwrap_or(0) as i64;
+
+    Some(score)
+}
+
+/// Outcome of offering selection through an external finder
+enum FinderOutcome {
+    /// No finder is installed; caller should fall back to the stdin prompt
+    Unavailable,
+    /// The finder was spawned but the user backed out of it (e.g. Esc)
+    Cancelled,
+    /// The user picked a project
+    Selected(String),
+}
+
+/// Prompts the user to pick a project from `pairs`, via an external finder if one is available
+/// and falling back to the numbered stdin prompt otherwise, then resolves the pick against `projects`
+fn prompt_selection<'a>(
+    pairs: &[(&'a String, &'a Project)],
+    projects: &'a Projects,
+    header: &str,
+    recurse_prompt: &str,
+) -> Selection<'a> {
+    let query = match select_with_finder(pairs, header)? {
+        FinderOutcome::Selected(project) => project,
+        FinderOutcome::Cancelled => bail!("Selection cancelled"),
+        FinderOutcome::Unavailable => {
+            print_project_pairs(pairs, header)?;
+            user_input("\nEnter project: ")?
+        }
+    };
+
+    select_project(&query, projects, recurse_prompt)
+}
+
+/// Pipes `pairs` into an external fuzzy finder (`fzf`/`sk`) and returns the chosen project name
+/// Returns `FinderOutcome::Unavailable` if no finder is installed, so callers can fall back to the
+/// stdin prompt; distinguishes that from `Cancelled`, where the finder ran but the user backed out
+fn select_with_finder(pairs: &[(&String, &Project)], prompt: &str) -> Result<FinderOutcome> {
+    let Some(finder) = resolve_finder() else {
+        return Ok(FinderOutcome::Unavailable);
+    };
+
+    let mut lines = Vec::with_capacity(pairs.len());
+    for (project, record) in pairs {
+        lines.push(format!("{project}\t{}", tilde_path(&record.path)?));
+    }
+
+    let mut args = vec![
+        "--delimiter".to_string(),
+        "\t".to_string(),
+        "--with-nth".to_string(),
+        "1".to_string(),
+        "--preview".to_string(),
+        "echo {2}".to_string(),
+    ];
+    if !prompt.is_empty() {
+        args.push("--prompt".to_string());
+        args.push(format!("{prompt} "));
+    }
+
+    let mut child = std::process::Command::new(&finder)
+        .args(&args)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to launch finder \"{finder}\""))?;
+
+    child
+        .stdin
+        .take()
+        .context("Failed to open finder stdin")?
+        .write_all(lines.join("\n").as_bytes())?;
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        // User backed out of the finder (e.g. Esc) or it errored; either way, honor the abort
+        // rather than silently falling through to the stdin prompt
+        return Ok(FinderOutcome::Cancelled);
+    }
+
+    let selected = String::from_utf8_lossy(&output.stdout);
+    match selected.trim().split_once('\t') {
+        Some((project, _)) => Ok(FinderOutcome::Selected(project.to_string())),
+        None => Ok(FinderOutcome::Cancelled),
+    }
+}
+
+/// Resolves which finder binary to use: `$FAST_FINDER` if set, else `fzf`, else `sk`, on `PATH`
+fn resolve_finder() -> Option<String> {
+    if let Some(finder) = env::var("FAST_FINDER").ok().filter(|f| !f.is_empty()) {
+        return Some(finder);
+    }
+
+    ["fzf", "sk"]
+        .into_iter()
+        .find(|finder| is_on_path(finder))
+        .map(String::from)
+}
+
+/// Checks whether an executable named `name` exists in any directory on `$PATH`
+fn is_on_path(name: &str) -> bool {
+    env::var_os("PATH").is_some_and(|paths| {
+        env::split_paths(&paths).any(|dir| dir.join(name).is_file())
+    })
+}
+
 /* Printing */
 
 fn print_help() {
@@ -289,13 +846,51 @@ Flags:
   -v, --view     View project in system file explorer
   -o, --open     Open project environment or IDE
   -e, --edit     Open project in $EDITOR
+  -c, --clone    Clone a git repository and save it as a project
+  --tag <tag>    Select only among projects carrying <tag> (load, delete, view, open, edit)
+  --tags         List distinct tags and how many projects carry each
   --reset        Reset list of projects"
     );
 }
 
-fn print_projects(projects: &Projects, prompt: &str) -> Result<()> {
+/// Prints the distinct tags across all projects, alongside how many projects carry each
+fn print_tags(projects: &Projects) -> Result<()> {
+    if projects.is_empty() {
+        bail!(NO_PROJECTS_ERROR);
+    }
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for project in projects.values() {
+        for tag in &project.tags {
+            *counts.entry(tag.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    if counts.is_empty() {
+        println!("No tags found");
+        return Ok(());
+    }
+
+    let mut tags: Vec<_> = counts.into_iter().collect();
+    tags.sort_by_key(|(tag, _)| *tag);
+
+    let padding = tags.iter().map(|(tag, _)| tag.len()).max().unwrap() + 2;
+    for (tag, count) in tags {
+        let suffix = if count != 1 { "s" } else { "" };
+        println!(
+            "\x1b[1m{: <width$}\x1b[0m{count} project{suffix}",
+            tag,
+            width = padding
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints project/record pairs in the order given, preceded by a count or prompt header
+fn print_project_pairs(pairs: &[(&String, &Project)], prompt: &str) -> Result<()> {
     if prompt.is_empty() {
-        let count = projects.len();
+        let count = pairs.len();
         let suffix = if count != 1 { "s" } else { "" };
         println!("{count} project{suffix} found\n");
     } else {
@@ -304,13 +899,12 @@ fn print_projects(projects: &Projects, prompt: &str) -> Result<()> {
 
     // Print two columns with project name on left in bold and path on right
     // Determine whitespace between columns using the maximum project length
-    let padding = projects.keys().map(String::len).max().unwrap() + 2;
-    let pairs = projects.iter().sorted();
-    for (project, path) in pairs {
+    let padding = pairs.iter().map(|(project, _)| project.len()).max().unwrap() + 2;
+    for (project, record) in pairs {
         println!(
             "\x1b[1m{: <width$}\x1b[0m{}",
             project,
-            tilde_path(path)?,
+            tilde_path(&record.path)?,
             width = padding
         );
     }
@@ -347,16 +941,64 @@ fn open_native(arg: &PathBuf) -> Result<()> {
         bail!("Unsupported OS");
     };
 
-    std::process::Command::new(command).arg(arg).spawn()?;
+    let mut cmd = std::process::Command::new(command);
+    cmd.arg(arg);
+    spawn_app(cmd)?;
     Ok(())
 }
 
+/// Spawns `cmd`, normalizing its environment on Linux first so apps launched from `fast`
+/// don't inherit a broken AppImage/snap/flatpak environment from the shell
+fn spawn_app(mut cmd: std::process::Command) -> Result<std::process::Child> {
+    if cfg!(target_os = "linux") {
+        normalize_linux_env(&mut cmd);
+    }
+
+    cmd.spawn().map_err(anyhow::Error::from)
+}
+
+/// De-duplicates `PATH`/`XDG_DATA_DIRS`, drops empty vars, and strips AppImage/snap/flatpak-injected
+/// library paths (`LD_LIBRARY_PATH`, `GST_PLUGIN_*`) that would otherwise leak into the child process
+fn normalize_linux_env(cmd: &mut std::process::Command) {
+    cmd.env_clear();
+
+    for (key, value) in env::vars() {
+        if value.is_empty() || key == "LD_LIBRARY_PATH" || key.starts_with("GST_PLUGIN_") {
+            continue;
+        }
+
+        if key == "PATH" || key == "XDG_DATA_DIRS" {
+            cmd.env(&key, dedup_path_list(&value));
+        } else {
+            cmd.env(&key, value);
+        }
+    }
+}
+
+/// Removes duplicate entries from a `:`-separated path list, preserving order
+fn dedup_path_list(value: &str) -> String {
+    let mut seen = HashSet::new();
+    let deduped: Vec<_> = env::split_paths(value)
+        .filter(|entry| seen.insert(entry.clone()))
+        .collect();
+
+    env::join_paths(deduped)
+        .map(|joined| joined.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| value.to_string())
+}
+
 /// Get path to data store in user's home directory
 fn get_store_path() -> Result<PathBuf> {
     let home = home_dir().context("Failed to retrieve data store path")?;
     Ok(home.join(".fstore"))
 }
 
+/// Get path to the user's open-handler config in their home directory
+fn get_fastrc_path() -> Result<PathBuf> {
+    let home = home_dir().context("Failed to retrieve config path")?;
+    Ok(home.join(".fastrc"))
+}
+
 /// Returns a path string replacing user's home directory with ~
 fn tilde_path(path: &Path) -> Result<String> {
     let home = home_dir()